@@ -0,0 +1,106 @@
+use error::Error;
+use client::Client;
+use resources::subscription::ListParams;
+use params::List;
+use serde_qs as qs;
+
+/// The kind of condition a `BillingAlert` watches for.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BillingAlertType {
+    UsageThreshold,
+}
+
+/// How often a `BillingAlert`'s usage threshold re-arms after firing.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BillingAlertRecurrence {
+    OneTime,
+}
+
+/// The status of a `BillingAlert`.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BillingAlertStatus {
+    Active,
+    Inactive,
+    Archived,
+}
+
+/// The usage threshold a `BillingAlert` fires against, keyed to a `BillingMeter`.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct BillingAlertUsageThreshold {
+    pub gte: u64,
+    pub meter: String,
+    pub recurrence: BillingAlertRecurrence,
+}
+
+/// The parameters used when creating a billing alert.
+///
+/// For more details see https://stripe.com/docs/api/billing/alert/create.
+#[derive(Serialize, Debug)]
+pub struct CreateBillingAlert<'a> {
+    pub alert_type: BillingAlertType,
+    pub title: &'a str,
+    pub usage_threshold: BillingAlertUsageThreshold,
+}
+
+/// The resource representing a Stripe billing alert.
+///
+/// Notifies when a customer's metered consumption, reported through the Billing Meters
+/// API, crosses a configured threshold.
+///
+/// For more details see https://stripe.com/docs/api/billing/alert.
+#[derive(Debug, Deserialize)]
+pub struct BillingAlert {
+    pub id: String,
+    pub alert_type: BillingAlertType,
+    pub title: String,
+    pub status: BillingAlertStatus,
+    pub usage_threshold: BillingAlertUsageThreshold,
+    pub livemode: bool,
+}
+
+impl BillingAlert {
+    /// Creates a billing alert.
+    ///
+    /// For more details see https://stripe.com/docs/api/billing/alert/create.
+    pub fn create(client: &Client, params: CreateBillingAlert) -> Result<BillingAlert, Error> {
+        client.post("/billing/alerts", params)
+    }
+
+    /// Retrieves the details of a billing alert.
+    ///
+    /// For more details see https://stripe.com/docs/api/billing/alert/retrieve.
+    pub fn retrieve(client: &Client, alert_id: &str) -> Result<BillingAlert, Error> {
+        client.get(&format!("/billing/alerts/{}", alert_id))
+    }
+
+    /// Lists billing alerts.
+    ///
+    /// For more details see https://stripe.com/docs/api/billing/alert/list.
+    pub fn list(client: &Client, params: ListParams) -> Result<List<BillingAlert>, Error> {
+        client.get(&format!("/billing/alerts?{}", qs::to_string(&params)?))
+    }
+
+    /// Activates a previously deactivated billing alert.
+    ///
+    /// For more details see https://stripe.com/docs/api/billing/alert/activate.
+    pub fn activate(client: &Client, alert_id: &str) -> Result<BillingAlert, Error> {
+        client.post(&format!("/billing/alerts/{}/activate", alert_id), ())
+    }
+
+    /// Deactivates a billing alert, so it stops firing until reactivated.
+    ///
+    /// For more details see https://stripe.com/docs/api/billing/alert/deactivate.
+    pub fn deactivate(client: &Client, alert_id: &str) -> Result<BillingAlert, Error> {
+        client.post(&format!("/billing/alerts/{}/deactivate", alert_id), ())
+    }
+
+    /// Archives a billing alert, permanently retiring it.
+    ///
+    /// For more details see https://stripe.com/docs/api/billing/alert/archive.
+    pub fn archive(client: &Client, alert_id: &str) -> Result<BillingAlert, Error> {
+        client.post(&format!("/billing/alerts/{}/archive", alert_id), ())
+    }
+}
@@ -0,0 +1,205 @@
+use std::collections::HashMap;
+use error::Error;
+use client::Client;
+use resources::subscription::ListParams;
+use params::{List, Timestamp};
+use serde_qs as qs;
+
+/// The aggregation formula used to turn a meter's events into a billable quantity.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MeterAggregationFormula {
+    Sum,
+    Count,
+    Last,
+}
+
+/// The status of a billing meter.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MeterStatus {
+    Active,
+    Inactive,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MeterDefaultAggregation {
+    pub formula: MeterAggregationFormula,
+}
+
+/// Maps a key in the meter event payload to the customer the event should be billed to.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MeterCustomerMapping {
+    pub event_payload_key: String,
+}
+
+/// The key in the meter event payload that holds the value to aggregate.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MeterValueSettings {
+    pub event_payload_key: String,
+}
+
+/// The set of parameters that can be used when creating a billing meter.
+///
+/// For more details see https://stripe.com/docs/api/billing/meter.
+#[derive(Serialize, Debug)]
+pub struct BillingMeterParams<'a> {
+    pub display_name: &'a str,
+    pub event_name: &'a str,
+    pub default_aggregation: MeterDefaultAggregation,
+    pub customer_mapping: MeterCustomerMapping,
+    pub value_settings: MeterValueSettings,
+}
+
+/// The parameters used when updating a billing meter.
+#[derive(Default, Serialize, Debug)]
+pub struct UpdateBillingMeterParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<&'a str>,
+}
+
+/// The resource representing a Stripe billing meter.
+///
+/// For more details see https://stripe.com/docs/api/billing/meter.
+#[derive(Debug, Deserialize)]
+pub struct BillingMeter {
+    pub id: String,
+    pub display_name: String,
+    pub event_name: String,
+    pub status: MeterStatus,
+    pub default_aggregation: MeterDefaultAggregation,
+    pub customer_mapping: MeterCustomerMapping,
+    pub value_settings: MeterValueSettings,
+    pub livemode: bool,
+    pub created: Timestamp,
+}
+
+impl BillingMeter {
+    /// Creates a new billing meter.
+    ///
+    /// For more details see https://stripe.com/docs/api/billing/meter/create.
+    pub fn create(client: &Client, params: BillingMeterParams) -> Result<BillingMeter, Error> {
+        client.post("/billing/meters", params)
+    }
+
+    /// Retrieves the details of a billing meter.
+    ///
+    /// For more details see https://stripe.com/docs/api/billing/meter/retrieve.
+    pub fn retrieve(client: &Client, meter_id: &str) -> Result<BillingMeter, Error> {
+        client.get(&format!("/billing/meters/{}", meter_id))
+    }
+
+    /// Updates a billing meter's display name.
+    ///
+    /// For more details see https://stripe.com/docs/api/billing/meter/update.
+    pub fn update(client: &Client, meter_id: &str, params: UpdateBillingMeterParams) -> Result<BillingMeter, Error> {
+        client.post(&format!("/billing/meters/{}", meter_id), params)
+    }
+
+    /// Lists billing meters.
+    ///
+    /// For more details see https://stripe.com/docs/api/billing/meter/list.
+    pub fn list(client: &Client, params: ListParams) -> Result<List<BillingMeter>, Error> {
+        client.get(&format!("/billing/meters?{}", qs::to_string(&params)?))
+    }
+
+    /// Deactivates a billing meter, stopping it from accepting new meter events.
+    ///
+    /// For more details see https://stripe.com/docs/api/billing/meter/deactivate.
+    pub fn deactivate(client: &Client, meter_id: &str) -> Result<BillingMeter, Error> {
+        client.post(&format!("/billing/meters/{}/deactivate", meter_id), ())
+    }
+
+    /// Reactivates a previously deactivated billing meter.
+    ///
+    /// For more details see https://stripe.com/docs/api/billing/meter/reactivate.
+    pub fn reactivate(client: &Client, meter_id: &str) -> Result<BillingMeter, Error> {
+        client.post(&format!("/billing/meters/{}/reactivate", meter_id), ())
+    }
+}
+
+/// The parameters used when reporting a meter event.
+///
+/// For more details see https://stripe.com/docs/api/billing/meter-event.
+#[derive(Serialize, Debug)]
+pub struct MeterEventParams<'a> {
+    pub event_name: &'a str,
+    pub payload: HashMap<String, String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub identifier: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timestamp: Option<Timestamp>,
+}
+
+/// The resource representing a Stripe meter event.
+///
+/// Meter events are the raw usage events reported against a `BillingMeter`; Stripe
+/// aggregates them according to the meter's `default_aggregation` to produce billable
+/// quantities.
+///
+/// For more details see https://stripe.com/docs/api/billing/meter-event.
+#[derive(Debug, Deserialize)]
+pub struct MeterEvent {
+    pub identifier: String,
+    pub event_name: String,
+    pub payload: HashMap<String, String>,
+    pub timestamp: Timestamp,
+    pub livemode: bool,
+}
+
+impl MeterEvent {
+    /// Creates a meter event, reporting usage for a `BillingMeter`.
+    ///
+    /// For more details see https://stripe.com/docs/api/billing/meter-event/create.
+    pub fn create(client: &Client, params: MeterEventParams) -> Result<MeterEvent, Error> {
+        client.post("/billing/meter_events", params)
+    }
+}
+
+/// The parameters used when cancelling a previously reported meter event.
+#[derive(Serialize, Debug)]
+pub struct MeterEventAdjustmentParams<'a> {
+    pub event_name: &'a str,
+    pub cancel: MeterEventAdjustmentCancel<'a>,
+    pub r#type: MeterEventAdjustmentType,
+}
+
+#[derive(Serialize, Debug)]
+pub struct MeterEventAdjustmentCancel<'a> {
+    pub identifier: &'a str,
+}
+
+/// The kind of adjustment to apply to a previously reported meter event.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MeterEventAdjustmentType {
+    Cancel,
+}
+
+/// The resource representing a Stripe meter event adjustment.
+///
+/// Used to void a `MeterEvent` that was reported in error, for example one with an
+/// incorrect `payload` or `identifier`.
+///
+/// For more details see https://stripe.com/docs/api/billing/meter-event-adjustment.
+#[derive(Debug, Deserialize)]
+pub struct MeterEventAdjustment {
+    pub event_name: String,
+    pub r#type: MeterEventAdjustmentType,
+    pub livemode: bool,
+}
+
+impl MeterEventAdjustment {
+    /// Cancels a previously reported meter event, identified by its client-supplied
+    /// `identifier`.
+    ///
+    /// For more details see https://stripe.com/docs/api/billing/meter-event-adjustment/create.
+    pub fn cancel(client: &Client, event_name: &str, identifier: &str) -> Result<MeterEventAdjustment, Error> {
+        let params = MeterEventAdjustmentParams {
+            event_name,
+            cancel: MeterEventAdjustmentCancel { identifier },
+            r#type: MeterEventAdjustmentType::Cancel,
+        };
+        client.post("/billing/meter_event_adjustments", params)
+    }
+}
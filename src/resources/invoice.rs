@@ -0,0 +1,67 @@
+use error::Error;
+use client::Client;
+use resources::subscription::ItemParams;
+use params::{List, Metadata, Timestamp};
+use serde_qs as qs;
+
+/// The parameters for `Invoice::upcoming`.
+///
+/// For more details see https://stripe.com/docs/api#upcoming_invoice.
+#[derive(Serialize, Debug)]
+pub struct RetrieveUpcomingInvoice<'a> {
+    pub customer: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subscription: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub coupon: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subscription_items: Option<Vec<ItemParams>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subscription_proration_date: Option<Timestamp>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subscription_prorate: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subscription_trial_end: Option<Timestamp>,
+}
+
+/// The resource representing a line item on a Stripe invoice, including proration entries.
+///
+/// For more details see https://stripe.com/docs/api#invoice_line_item_object.
+#[derive(Debug, Deserialize)]
+pub struct InvoiceLineItem {
+    pub id: String,
+    pub amount: i64,
+    pub currency: String,
+    pub description: Option<String>,
+    pub proration: bool,
+    pub period_start: Timestamp,
+    pub period_end: Timestamp,
+    pub subscription_item: Option<String>,
+}
+
+/// The resource representing a Stripe invoice.
+///
+/// For more details see https://stripe.com/docs/api#invoices.
+#[derive(Debug, Deserialize)]
+pub struct Invoice {
+    pub id: Option<String>,
+    pub customer: String,
+    pub subscription: Option<String>,
+    pub amount_due: i64,
+    pub currency: String,
+    pub lines: List<InvoiceLineItem>,
+    pub livemode: bool,
+    pub metadata: Metadata,
+    pub date: Timestamp,
+    pub paid: bool,
+}
+
+impl Invoice {
+    /// Previews the upcoming invoice for a customer, including the proration that would
+    /// result from a pending subscription change.
+    ///
+    /// For more details see https://stripe.com/docs/api#upcoming_invoice.
+    pub fn upcoming(client: &Client, params: RetrieveUpcomingInvoice) -> Result<Invoice, Error> {
+        client.get(&format!("/invoices/upcoming?{}", qs::to_string(&params)?))
+    }
+}
@@ -0,0 +1,23 @@
+mod billing_alert;
+mod billing_meter;
+mod invoice;
+mod subscription;
+
+pub use self::billing_alert::{
+    BillingAlert, BillingAlertRecurrence, BillingAlertStatus, BillingAlertType,
+    BillingAlertUsageThreshold, CreateBillingAlert,
+};
+pub use self::billing_meter::{
+    BillingMeter, BillingMeterParams, MeterAggregationFormula, MeterCustomerMapping,
+    MeterDefaultAggregation, MeterEvent, MeterEventAdjustment, MeterEventAdjustmentCancel,
+    MeterEventAdjustmentParams, MeterEventAdjustmentType, MeterEventParams, MeterStatus,
+    MeterValueSettings, UpdateBillingMeterParams,
+};
+pub use self::invoice::{Invoice, InvoiceLineItem, RetrieveUpcomingInvoice};
+pub use self::subscription::{
+    CancelParams, CollectionMethod, CustomerId, ItemBillingThresholds, ItemParams, ListParams,
+    ParseIdError, PauseCollection, PauseCollectionBehavior, PlanId, Subscription,
+    SubscriptionBillingThresholds, SubscriptionId, SubscriptionItem, SubscriptionItemId,
+    SubscriptionParams, SubscriptionStatus, TrialEnd, UsageRecord, UsageRecordAction,
+    UsageRecordParams, UsageRecordSummary, UsageRecordSummaryPeriod, UsageThresholdActionError,
+};
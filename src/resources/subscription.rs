@@ -1,3 +1,7 @@
+use std::fmt;
+use std::str::FromStr;
+use serde::{Serialize, Serializer, Deserialize, Deserializer};
+use serde::de::Error as DeError;
 use error::Error;
 use client::Client;
 use resources::{Discount, Plan};
@@ -5,6 +9,139 @@ use params::{List, Metadata, Timestamp};
 use serde_qs as qs;
 use chrono::Utc;
 
+/// An error returned when parsing a string as a typed resource id whose value does not
+/// start with the id's expected object prefix (e.g. `sub_` for a `SubscriptionId`).
+#[derive(Debug)]
+pub struct ParseIdError {
+    typename: &'static str,
+    expected_prefix: &'static str,
+}
+
+impl fmt::Display for ParseIdError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "invalid {}: expected an id starting with `{}`", self.typename, self.expected_prefix)
+    }
+}
+
+impl ::std::error::Error for ParseIdError {
+    fn description(&self) -> &str {
+        "error parsing a stripe id"
+    }
+}
+
+/// Defines a newtype wrapper around `String` for a Stripe object id, validating that it
+/// carries the object's expected prefix (e.g. `cus_` for a customer) on parse. Wrapping
+/// each id kind in its own type means passing, say, a customer id where a subscription
+/// id is expected is caught by the compiler rather than surfacing as an API error.
+macro_rules! def_id {
+    ($struct_name:ident, $prefix:expr) => {
+        #[derive(Clone, Debug, Eq, Hash, PartialEq, Ord, PartialOrd)]
+        pub struct $struct_name(String);
+
+        impl $struct_name {
+            pub fn as_str(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl AsRef<str> for $struct_name {
+            fn as_ref(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl fmt::Display for $struct_name {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str(&self.0)
+            }
+        }
+
+        impl FromStr for $struct_name {
+            type Err = ParseIdError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                if !s.starts_with($prefix) {
+                    return Err(ParseIdError { typename: stringify!($struct_name), expected_prefix: $prefix });
+                }
+                Ok($struct_name(s.to_string()))
+            }
+        }
+
+        impl Serialize for $struct_name {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.serialize_str(&self.0)
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $struct_name {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let s = String::deserialize(deserializer)?;
+                $struct_name::from_str(&s).map_err(DeError::custom)
+            }
+        }
+    };
+}
+
+/// Like `def_id!`, but for ids that are not always Stripe-generated and so cannot be
+/// assumed to carry an object prefix (e.g. a plan may be created with a caller-chosen
+/// id such as `"gold-monthly"`). Parsing never fails; use `new` to wrap such an id
+/// explicitly.
+macro_rules! def_id_unchecked {
+    ($struct_name:ident) => {
+        #[derive(Clone, Debug, Eq, Hash, PartialEq, Ord, PartialOrd)]
+        pub struct $struct_name(String);
+
+        impl $struct_name {
+            /// Wraps an arbitrary string as this id, without requiring an object prefix.
+            pub fn new<S: Into<String>>(id: S) -> Self {
+                $struct_name(id.into())
+            }
+
+            pub fn as_str(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl AsRef<str> for $struct_name {
+            fn as_ref(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl fmt::Display for $struct_name {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str(&self.0)
+            }
+        }
+
+        impl FromStr for $struct_name {
+            type Err = ParseIdError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                Ok($struct_name(s.to_string()))
+            }
+        }
+
+        impl Serialize for $struct_name {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.serialize_str(&self.0)
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $struct_name {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let s = String::deserialize(deserializer)?;
+                Ok($struct_name(s))
+            }
+        }
+    };
+}
+
+def_id!(SubscriptionId, "sub_");
+def_id!(SubscriptionItemId, "si_");
+def_id!(CustomerId, "cus_");
+def_id_unchecked!(PlanId);
+
 #[derive(Default, Serialize)]
 pub struct CancelParams {
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -12,10 +149,32 @@ pub struct CancelParams {
 }
 
 #[derive(Serialize, Debug)]
-pub struct ItemParams<'a> {
-    pub plan: &'a str,
+pub struct ItemParams {
+    pub plan: PlanId,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub quantity: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub billing_thresholds: Option<ItemBillingThresholds>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tax_rates: Option<Vec<String>>,
+}
+
+/// A per-item usage threshold; once crossed, Stripe invoices the subscription early
+/// instead of waiting for the billing period to end.
+#[derive(Serialize, Debug, Deserialize)]
+pub struct ItemBillingThresholds {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub usage_gte: Option<u64>,
+}
+
+/// A subscription-level billing threshold; once the invoice amount crosses `amount_gte`,
+/// Stripe invoices the subscription early instead of waiting for the billing period to end.
+#[derive(Serialize, Debug, Deserialize)]
+pub struct SubscriptionBillingThresholds {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount_gte: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reset_billing_cycle_anchor: Option<bool>,
 }
 
 /// The set of parameters that can be used when creating or updating a subscription.
@@ -28,12 +187,22 @@ pub struct SubscriptionParams<'a> {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub application_fee_percent: Option<f64>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub billing_thresholds: Option<SubscriptionBillingThresholds>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub collection_method: Option<CollectionMethod>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub coupon: Option<&'a str>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub items: Option<Vec<ItemParams<'a>>>,
+    pub days_until_due: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_payment_method: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub items: Option<Vec<ItemParams>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub metadata: Option<Metadata>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub pause_collection: Option<PauseCollection>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub plan: Option<&'a str>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub prorate: Option<bool>,
@@ -51,6 +220,45 @@ pub struct SubscriptionParams<'a> {
     pub trial_period_days: Option<u64>,
 }
 
+/// How Stripe collects payment for invoices on a subscription.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CollectionMethod {
+    ChargeAutomatically,
+    SendInvoice,
+}
+
+/// What happens to invoices generated while a subscription's invoicing is paused.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PauseCollectionBehavior {
+    KeepAsDraft,
+    MarkUncollectible,
+    Void,
+}
+
+/// Pauses collection of invoices for a subscription until `resumes_at`, or indefinitely
+/// if unset.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PauseCollection {
+    pub behavior: PauseCollectionBehavior,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resumes_at: Option<Timestamp>,
+}
+
+/// The status of a subscription, tracking where it is in its billing lifecycle.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SubscriptionStatus {
+    Trialing,
+    Active,
+    PastDue,
+    Canceled,
+    Unpaid,
+    Incomplete,
+    IncompleteExpired,
+}
+
 
 #[derive(Serialize, Debug)]
 #[serde(untagged)]
@@ -64,10 +272,12 @@ pub enum TrialEnd<'a> {
 /// For more details see https://stripe.com/docs/api#subscription_items.
 #[derive(Debug, Deserialize)]
 pub struct SubscriptionItem {
-    pub id: String,
+    pub id: SubscriptionItemId,
+    pub billing_thresholds: Option<ItemBillingThresholds>,
     pub created: Timestamp,
     pub plan: Plan,
     pub quantity: Option<u64>,
+    pub tax_rates: Option<Vec<String>>,
 }
 
 /// The resource representing a Stripe subscription.
@@ -75,23 +285,29 @@ pub struct SubscriptionItem {
 /// For more details see https://stripe.com/docs/api#subscriptions.
 #[derive(Debug, Deserialize)]
 pub struct Subscription {
-    pub id: String,
+    pub id: SubscriptionId,
     pub application_fee_percent: Option<f64>,
+    pub billing_thresholds: Option<SubscriptionBillingThresholds>,
     pub cancel_at_period_end: bool,
     pub canceled_at: Option<Timestamp>,
+    pub collection_method: Option<CollectionMethod>,
     pub created: Option<Timestamp>,
     pub current_period_start: Timestamp,
     pub current_period_end: Timestamp,
-    pub customer: String,
+    pub customer: CustomerId,
+    pub days_until_due: Option<u64>,
+    pub default_payment_method: Option<String>,
     pub discount: Option<Discount>,
     pub ended_at: Option<Timestamp>,
     pub items: List<SubscriptionItem>,
+    pub latest_invoice: Option<String>,
     pub livemode: bool,
     pub metadata: Metadata,
+    pub pause_collection: Option<PauseCollection>,
     pub plan: Plan,
     pub quantity: Option<u64>,
     pub start: Timestamp,
-    pub status: String, // (trialing, active, past_due, canceled, unpaid)
+    pub status: SubscriptionStatus,
     pub tax_percent: Option<f64>,
     pub trial_start: Option<Timestamp>,
     pub trial_end: Option<Timestamp>,
@@ -108,25 +324,36 @@ impl Subscription {
     /// Retrieves the details of a subscription.
     ///
     /// For more details see https://stripe.com/docs/api#retrieve_subscription.
-    pub fn retrieve(client: &Client, subscription_id: &str) -> Result<Subscription, Error> {
+    pub fn retrieve(client: &Client, subscription_id: &SubscriptionId) -> Result<Subscription, Error> {
         client.get(&format!("/subscriptions/{}", subscription_id))
     }
 
     /// Updates a subscription's properties.
     /// For more details see https://stripe.com/docs/api#update_subscription.
-    pub fn update(client: &Client, subscription_id: &str, params: SubscriptionParams) -> Result<Subscription, Error> {
+    pub fn update(client: &Client, subscription_id: &SubscriptionId, params: SubscriptionParams) -> Result<Subscription, Error> {
         client.post(&format!("/subscriptions/{}", subscription_id), params)
     }
 
     /// Cancels a subscription.
     ///
     /// For more details see https://stripe.com/docs/api#cancel_subscription.
-    pub fn cancel(client: &Client, subscription_id: &str, params: CancelParams) -> Result<Subscription, Error> {
+    pub fn cancel(client: &Client, subscription_id: &SubscriptionId, params: CancelParams) -> Result<Subscription, Error> {
         client.delete(&format!("/subscriptions/{}?{}", subscription_id, qs::to_string(&params)?))
     }
 }
 
 
+/// Pagination parameters shared by the list endpoints in this module.
+#[derive(Default, Serialize, Debug)]
+pub struct ListParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<&'a str>,
+}
+
 /// The parameters to create a Stripe usage record.
 ///
 /// For more details see https://stripe.com/docs/api#usage_records.
@@ -135,30 +362,58 @@ pub struct UsageRecordParams {
     pub timestamp: Timestamp,
     pub quantity: u64,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub action: Option<String>,
+    pub action: Option<UsageRecordAction>,
 }
 
 /// The type of action to apply to the usage record quantity
+#[derive(Debug, Clone, Copy, Serialize)]
+#[serde(rename_all = "snake_case")]
 pub enum UsageRecordAction {
     Increment,
     Set,
 }
 
-impl UsageRecordAction {
-    fn name(&self) -> String {
-        match self {
-            &UsageRecordAction::Increment => "increment".into(),
-            &UsageRecordAction::Set => "set".into(),
-        }
+/// Returned by `UsageRecordParams::create` when asked to report the `Set` action
+/// against a subscription item that has a usage-based billing threshold; Stripe only
+/// accepts `Increment` in that case.
+#[derive(Debug)]
+pub struct UsageThresholdActionError;
+
+impl fmt::Display for UsageThresholdActionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "subscription items with a usage threshold only support the `increment` action")
+    }
+}
+
+impl ::std::error::Error for UsageThresholdActionError {
+    fn description(&self) -> &str {
+        "invalid usage record action for a thresholded item"
     }
 }
 
 impl UsageRecordParams {
-    /// Create a usage record, the default action is Increment
-    pub fn create(quantity: u64, action: Option<UsageRecordAction>) -> UsageRecordParams {
+    /// Create a usage record, the default action is Increment.
+    ///
+    /// Pass the reporting item's own `billing_thresholds` (e.g. from
+    /// `SubscriptionItem.billing_thresholds`) as `item_billing_thresholds`. Stripe only
+    /// allows the `Increment` action on an item with a usage threshold set, so
+    /// requesting `Set` against one is rejected here rather than failing later as an
+    /// API error.
+    pub fn create(
+        quantity: u64,
+        action: Option<UsageRecordAction>,
+        item_billing_thresholds: Option<&ItemBillingThresholds>,
+    ) -> Result<UsageRecordParams, UsageThresholdActionError> {
+        let has_usage_threshold = item_billing_thresholds.map_or(false, |t| t.usage_gte.is_some());
+        let sets_thresholded_item = has_usage_threshold && match action {
+            Some(UsageRecordAction::Set) => true,
+            _ => false,
+        };
+        if sets_thresholded_item {
+            return Err(UsageThresholdActionError);
+        }
         let timestamp = Utc::now().timestamp();
-        let action = action.map(|a| a.name());
-        UsageRecordParams{ timestamp, quantity, action }
+        Ok(UsageRecordParams{ timestamp, quantity, action })
     }
 }
 
@@ -171,7 +426,7 @@ pub struct UsageRecord {
     pub object: String,
     pub livemode: bool,
     pub quantity: u64,
-    pub subscription_item: String,
+    pub subscription_item: SubscriptionItemId,
     pub timestamp: Timestamp,
 }
 
@@ -180,7 +435,36 @@ impl UsageRecord {
     /// Creates a new subscription for a customer.
     ///
     /// For more details see https://stripe.com/docs/api#create_subscription.
-    pub fn create(client: &Client, subscription_item_id: &str, params: UsageRecordParams) -> Result<UsageRecord, Error> {
+    pub fn create(client: &Client, subscription_item_id: &SubscriptionItemId, params: UsageRecordParams) -> Result<UsageRecord, Error> {
         client.post(&format!("/subscription_items/{}/usage_records", subscription_item_id), params)
     }
-}
\ No newline at end of file
+}
+
+/// The billing period a `UsageRecordSummary` aggregates usage over.
+#[derive(Debug, Deserialize)]
+pub struct UsageRecordSummaryPeriod {
+    pub start: Timestamp,
+    pub end: Timestamp,
+}
+
+/// The resource representing a Stripe usage record summary: the aggregated usage for a
+/// metered subscription item over a billing period.
+///
+/// For more details see https://stripe.com/docs/api#usage_record_summary_object.
+#[derive(Debug, Deserialize)]
+pub struct UsageRecordSummary {
+    pub id: String,
+    pub invoice: Option<String>,
+    pub subscription_item: SubscriptionItemId,
+    pub period: UsageRecordSummaryPeriod,
+    pub total_usage: u64,
+}
+
+impl UsageRecordSummary {
+    /// Lists the usage record summaries for a subscription item, most recent first.
+    ///
+    /// For more details see https://stripe.com/docs/api#usage_record_summary_list.
+    pub fn list(client: &Client, subscription_item_id: &SubscriptionItemId, params: ListParams) -> Result<List<UsageRecordSummary>, Error> {
+        client.get(&format!("/subscription_items/{}/usage_record_summaries?{}", subscription_item_id, qs::to_string(&params)?))
+    }
+}